@@ -68,23 +68,189 @@
 /// let also_my_conf = match Configuration::new() {
 ///     Ok(conf) => conf,
 ///     Err(ConfigError::ConfigMissing(reason)) => panic!("'{reason}' not found"),
-///     Err(ConfigError::InvalidData(reason)) => panic!("'{reason}' not parseable")
+///     Err(ConfigError::InvalidData(reason)) => panic!("'{reason}' not parseable"),
+///     Err(ConfigError::Multiple(errors)) => panic!("{} values are invalid: {errors:?}", errors.len())
 /// };
 /// assert_eq!(also_my_conf.your_name, "Brad");
 /// assert_eq!(also_my_conf.your_age, 20u32);
 /// ```
 ///
+/// # Defaults
+///
+/// Any entry can carry a trailing `= $default` so a missing variable falls back to
+/// that value instead of producing a `ConfigError::ConfigMissing`. The default is only
+/// used when the variable is absent; a value that is present but fails to parse still
+/// returns `ConfigError::InvalidData`.
+///
+/// ```
+/// mod my_conf {
+///     cola::make_conf! [
+///         "EX_PORT" => port: u16 = 8080
+///     ];
+/// }
+///
+/// let conf = my_conf::Configuration::default();
+/// assert_eq!(conf.port, 8080);
+/// ```
+///
+/// # Lists
+///
+/// A field typed as `Vec<T>` is read as a delimited list; each element is run
+/// through the same parsing `T` would get on its own, e.g. `"EX_HOSTS" => hosts: Vec<String>`
+/// turns `EX_HOSTS=a,b,c` into `vec!["a", "b", "c"]`. An element that fails to parse
+/// still surfaces as `ConfigError::InvalidData` naming that element.
+///
+/// The separator defaults to `,` and can be overridden with a trailing `sep '...'`,
+/// e.g. `"EX_HOSTS" => hosts: Vec<String> sep ';'`.
+///
+/// ```
+/// std::env::set_var("EX_HOSTS", "alpha, beta, gamma");
+/// std::env::set_var("EX_PORTS", "80;443");
+///
+/// mod my_conf {
+///     cola::make_conf! [
+///         "EX_HOSTS" => hosts: Vec<String>,
+///         "EX_PORTS" => ports: Vec<u16> sep ';'
+///     ];
+/// }
+///
+/// let conf = my_conf::Configuration::default();
+/// assert_eq!(conf.hosts, vec!["alpha", "beta", "gamma"]);
+/// assert_eq!(conf.ports, vec![80, 443]);
+/// ```
+///
+/// # Diagnosing every misconfigured field at once
+///
+/// `new` fails as soon as it hits the first bad field. The generated `new_all`
+/// instead evaluates every field and, if more than one went wrong, reports all of
+/// them together as `ConfigError::Multiple`, so a user fixing a few misconfigured
+/// variables doesn't have to run their program once per mistake.
+///
+/// ```
+/// mod my_conf {
+///     cola::make_conf! [
+///         "EX_MISSING_ONE" => one: String,
+///         "EX_MISSING_TWO" => two: String
+///     ];
+/// }
+///
+/// use cola::ConfigError;
+/// match my_conf::Configuration::new_all() {
+///     Err(ConfigError::Multiple(errors)) => assert_eq!(errors.len(), 2),
+///     Err(err) => panic!("should not panic {err:?}"),
+///     Ok(_) => panic!("should not be ok"),
+/// }
+/// ```
+///
+/// # Layered file + environment sources
+///
+/// `Configuration::read_from(path)` layers a simple `key = value` file (a safe
+/// subset of TOML) underneath the environment: for each field, a present
+/// environment variable wins, otherwise the matching key in the file is used,
+/// otherwise the field's default, if any, applies.
+///
+/// ```
+/// # std::env::set_var("EX_FROM_ENV", "env wins");
+/// let path = std::env::temp_dir().join("cola_make_conf_doctest.toml");
+/// std::fs::write(&path, "from_file = \"file value\"\nEX_FROM_ENV = \"file value\"\n").unwrap();
+///
+/// mod my_conf {
+///     cola::make_conf! [
+///         "from_file" => from_file: String,
+///         "EX_FROM_ENV" => from_env: String
+///     ];
+/// }
+///
+/// let conf = my_conf::Configuration::read_from(&path).unwrap();
+/// assert_eq!(conf.from_file, "file value");
+/// assert_eq!(conf.from_env, "env wins");
+///
+/// std::fs::remove_file(&path).unwrap();
+/// ```
+///
+/// # Optional fields
+///
+/// A field typed as `Option<T>` is truly optional: a missing variable resolves to
+/// `None` instead of a `ConfigError::ConfigMissing`, while a present-but-unparseable
+/// value still returns `ConfigError::InvalidData`.
+///
+/// ```
+/// std::env::remove_var("EX_FEATURE_FLAG");
+///
+/// mod my_conf {
+///     cola::make_conf! [
+///         "EX_FEATURE_FLAG" => flag: Option<bool>
+///     ];
+/// }
+///
+/// let conf = my_conf::Configuration::default();
+/// assert_eq!(conf.flag, None);
+/// ```
+///
+/// # Shared prefix
+///
+/// A leading `prefix "LITERAL";` applies a shared prefix to every key in the block,
+/// so entries can drop the part they all have in common. The prefix must be a
+/// string literal, since it's spliced into `#[doc]` attributes on the generated
+/// fields alongside each key; the generated field docs show the fully-qualified
+/// variable name.
+///
+/// ```
+/// std::env::set_var("EX_YOUR_NAME", "Brad");
+///
+/// mod my_conf {
+///     cola::make_conf! [
+///         prefix "EX_";
+///         "YOUR_NAME" => your_name: String
+///     ];
+/// }
+///
+/// let conf = my_conf::Configuration::default();
+/// assert_eq!(conf.your_name, "Brad");
+/// ```
+///
 macro_rules! make_conf {
-    ( $( $x:expr => $n:ident: $t:ty ), * ) => {
+    ( prefix $prefix:literal; $( $input:tt )* ) => {
+        $crate::__cola_munch! {
+            @prefix $prefix
+            @errors __cola_errors
+            @table __cola_table
+            @fields []
+            @ctors []
+            @checks []
+            @names []
+            @reads []
+            @input $($input)*
+        }
+    };
+    ( $( $input:tt )* ) => {
+        $crate::__cola_munch! {
+            @prefix ""
+            @errors __cola_errors
+            @table __cola_table
+            @fields []
+            @ctors []
+            @checks []
+            @names []
+            @reads []
+            @input $($input)*
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// Implementation detail of <make_conf>: walks the entry list one field at a time so
+/// that each field's shape (plain, `Vec<_>`, ...) can be matched against its raw,
+/// not-yet-typed tokens. Not part of the public API.
+macro_rules! __cola_munch {
+    // Done: no more entries, emit the struct and its impls.
+    (@prefix $prefix:literal @errors $errors:ident @table $table:ident @fields [$($field:tt)*] @ctors [$($ctor:tt)*] @checks [$($check:tt)*] @names [$($name:tt)*] @reads [$($read:tt)*] @input) => {
         use $crate::ConfigError;
 
         /// App configuration, wrapped up into a neat package.
         pub struct Configuration {
-            $(
-                #[doc="This value represents the data stored in the environment variable "]
-                #[doc=$x]
-                pub $n: $t,
-            )*
+            $($field)*
         }
 
         impl Default for Configuration {
@@ -92,28 +258,266 @@ macro_rules! make_conf {
                 match Configuration::new() {
                     Ok(config) => config,
                     Err(ConfigError::ConfigMissing(reason)) => panic!("The value {reason} is missing"),
-                    Err(ConfigError::InvalidData(reason)) => panic!("The data stored in {reason} is non-parseable")
+                    Err(ConfigError::InvalidData(reason)) => panic!("The data stored in {reason} is non-parseable"),
+                    Err(ConfigError::Multiple(errors)) => panic!("{} configuration values are invalid: {errors:?}", errors.len())
                 }
             }
         }
 
         impl Configuration {
             /// Loads application configuration.
-            $(
-                /// (
-                #[doc = $x]
-                /// )
-            )*
-            ///
             pub fn new() -> Result<Configuration, ConfigError> {
                 Ok(Self {
-                    $(
-                        $n: $crate::convert::<$t>($crate::parse_env($x)?)?,
-                    )*
+                    $($ctor)*
+                })
+            }
+
+            /// Loads application configuration, first seeding the process environment
+            /// from a `.env` file at `path`.
+            ///
+            /// Variables already set in the real environment take precedence over the
+            /// file; see <cola::load_dotenv>.
+            ///
+            /// # Errors
+            /// - <ConfigError::ConfigMissing>
+            /// - <ConfigError::InvalidData>
+            pub fn from_env_file<P: AsRef<std::path::Path>>(path: P) -> Result<Configuration, ConfigError> {
+                $crate::load_dotenv(path);
+                Self::new()
+            }
+
+            /// Loads application configuration like <Configuration::new>, but evaluates
+            /// every field instead of stopping at the first problem.
+            ///
+            /// # Errors
+            /// - <ConfigError::ConfigMissing>
+            /// - <ConfigError::InvalidData>
+            /// - <ConfigError::Multiple>, when more than one field is missing or invalid
+            pub fn new_all() -> Result<Configuration, ConfigError> {
+                let mut $errors: Vec<ConfigError> = Vec::new();
+
+                $($check)*
+
+                if $errors.len() == 1 {
+                    return Err($errors.remove(0));
+                }
+                if !$errors.is_empty() {
+                    return Err(ConfigError::Multiple($errors));
+                }
+
+                Ok(Self {
+                    $($name)*
+                })
+            }
+
+            /// Loads application configuration layered from a simple TOML-like `path`,
+            /// with any present environment variable overriding the file and any
+            /// declared default applying last.
+            ///
+            /// For each field, in order of precedence: the environment variable, then
+            /// the matching key in `path`, then the field's default, if any; see
+            /// <cola::read_table>.
+            ///
+            /// # Errors
+            /// - <ConfigError::ConfigMissing>
+            /// - <ConfigError::InvalidData>
+            pub fn read_from<P: AsRef<std::path::Path>>(path: P) -> Result<Configuration, ConfigError> {
+                let $table = $crate::read_table(path);
+
+                Ok(Self {
+                    $($read)*
                 })
             }
         }
-    }
+    };
+
+    // An `Option<T>` entry, with more entries following.
+    (@prefix $prefix:literal @errors $errors:ident @table $table:ident @fields [$($field:tt)*] @ctors [$($ctor:tt)*] @checks [$($check:tt)*] @names [$($name:tt)*] @reads [$($read:tt)*] @input $x:expr => $n:ident: Option<$t:ty>, $($rest:tt)*) => {
+        $crate::__cola_munch! {
+            @prefix $prefix
+            @errors $errors
+            @table $table
+            @fields [$($field)* #[doc = "This value represents the data stored in the environment variable "] #[doc = concat!($prefix, $x)] #[doc = "`None` when the variable is unset."] pub $n: Option<$t>,]
+            @ctors [$($ctor)* $n: $crate::__cola_field_value!(concat!($prefix, $x), Option<$t>),]
+            @checks [$($check)* let $n = match $crate::__cola_field_result!(concat!($prefix, $x), Option<$t>) { Ok(value) => Some(value), Err(err) => { $errors.push(err); None } };]
+            @names [$($name)* $n: $n.expect("validated above"),]
+            @reads [$($read)* $n: $crate::__cola_field_from_table!(&$table, concat!($prefix, $x), Option<$t>),]
+            @input $($rest)*
+        }
+    };
+    // An `Option<T>` entry, the last one in the list.
+    (@prefix $prefix:literal @errors $errors:ident @table $table:ident @fields [$($field:tt)*] @ctors [$($ctor:tt)*] @checks [$($check:tt)*] @names [$($name:tt)*] @reads [$($read:tt)*] @input $x:expr => $n:ident: Option<$t:ty>) => {
+        $crate::__cola_munch! {
+            @prefix $prefix
+            @errors $errors
+            @table $table
+            @fields [$($field)* #[doc = "This value represents the data stored in the environment variable "] #[doc = concat!($prefix, $x)] #[doc = "`None` when the variable is unset."] pub $n: Option<$t>,]
+            @ctors [$($ctor)* $n: $crate::__cola_field_value!(concat!($prefix, $x), Option<$t>),]
+            @checks [$($check)* let $n = match $crate::__cola_field_result!(concat!($prefix, $x), Option<$t>) { Ok(value) => Some(value), Err(err) => { $errors.push(err); None } };]
+            @names [$($name)* $n: $n.expect("validated above"),]
+            @reads [$($read)* $n: $crate::__cola_field_from_table!(&$table, concat!($prefix, $x), Option<$t>),]
+            @input
+        }
+    };
+
+    // A `Vec<T>` entry, with more entries following.
+    (@prefix $prefix:literal @errors $errors:ident @table $table:ident @fields [$($field:tt)*] @ctors [$($ctor:tt)*] @checks [$($check:tt)*] @names [$($name:tt)*] @reads [$($read:tt)*] @input $x:expr => $n:ident: Vec<$t:ty> $(sep $sep:literal)? $(= $default:expr)?, $($rest:tt)*) => {
+        $crate::__cola_munch! {
+            @prefix $prefix
+            @errors $errors
+            @table $table
+            @fields [$($field)* #[doc = "This value represents the data stored in the environment variable "] #[doc = concat!($prefix, $x)] pub $n: Vec<$t>,]
+            @ctors [$($ctor)* $n: $crate::__cola_field_value!(concat!($prefix, $x), Vec<$t> $(, sep $sep)? $(, $default)?),]
+            @checks [$($check)* let $n = match $crate::__cola_field_result!(concat!($prefix, $x), Vec<$t> $(, sep $sep)? $(, $default)?) { Ok(value) => Some(value), Err(err) => { $errors.push(err); None } };]
+            @names [$($name)* $n: $n.expect("validated above"),]
+            @reads [$($read)* $n: $crate::__cola_field_from_table!(&$table, concat!($prefix, $x), Vec<$t> $(, sep $sep)? $(, $default)?),]
+            @input $($rest)*
+        }
+    };
+    // A `Vec<T>` entry, the last one in the list.
+    (@prefix $prefix:literal @errors $errors:ident @table $table:ident @fields [$($field:tt)*] @ctors [$($ctor:tt)*] @checks [$($check:tt)*] @names [$($name:tt)*] @reads [$($read:tt)*] @input $x:expr => $n:ident: Vec<$t:ty> $(sep $sep:literal)? $(= $default:expr)?) => {
+        $crate::__cola_munch! {
+            @prefix $prefix
+            @errors $errors
+            @table $table
+            @fields [$($field)* #[doc = "This value represents the data stored in the environment variable "] #[doc = concat!($prefix, $x)] pub $n: Vec<$t>,]
+            @ctors [$($ctor)* $n: $crate::__cola_field_value!(concat!($prefix, $x), Vec<$t> $(, sep $sep)? $(, $default)?),]
+            @checks [$($check)* let $n = match $crate::__cola_field_result!(concat!($prefix, $x), Vec<$t> $(, sep $sep)? $(, $default)?) { Ok(value) => Some(value), Err(err) => { $errors.push(err); None } };]
+            @names [$($name)* $n: $n.expect("validated above"),]
+            @reads [$($read)* $n: $crate::__cola_field_from_table!(&$table, concat!($prefix, $x), Vec<$t> $(, sep $sep)? $(, $default)?),]
+            @input
+        }
+    };
+
+    // A plain entry, with more entries following.
+    (@prefix $prefix:literal @errors $errors:ident @table $table:ident @fields [$($field:tt)*] @ctors [$($ctor:tt)*] @checks [$($check:tt)*] @names [$($name:tt)*] @reads [$($read:tt)*] @input $x:expr => $n:ident: $t:ty $(= $default:expr)?, $($rest:tt)*) => {
+        $crate::__cola_munch! {
+            @prefix $prefix
+            @errors $errors
+            @table $table
+            @fields [$($field)* #[doc = "This value represents the data stored in the environment variable "] #[doc = concat!($prefix, $x)] $(#[doc = concat!("Defaults to `", stringify!($default), "` if unset.")])? pub $n: $t,]
+            @ctors [$($ctor)* $n: $crate::__cola_field_value!(concat!($prefix, $x), $t $(, $default)?),]
+            @checks [$($check)* let $n = match $crate::__cola_field_result!(concat!($prefix, $x), $t $(, $default)?) { Ok(value) => Some(value), Err(err) => { $errors.push(err); None } };]
+            @names [$($name)* $n: $n.expect("validated above"),]
+            @reads [$($read)* $n: $crate::__cola_field_from_table!(&$table, concat!($prefix, $x), $t $(, $default)?),]
+            @input $($rest)*
+        }
+    };
+    // A plain entry, the last one in the list.
+    (@prefix $prefix:literal @errors $errors:ident @table $table:ident @fields [$($field:tt)*] @ctors [$($ctor:tt)*] @checks [$($check:tt)*] @names [$($name:tt)*] @reads [$($read:tt)*] @input $x:expr => $n:ident: $t:ty $(= $default:expr)?) => {
+        $crate::__cola_munch! {
+            @prefix $prefix
+            @errors $errors
+            @table $table
+            @fields [$($field)* #[doc = "This value represents the data stored in the environment variable "] #[doc = concat!($prefix, $x)] $(#[doc = concat!("Defaults to `", stringify!($default), "` if unset.")])? pub $n: $t,]
+            @ctors [$($ctor)* $n: $crate::__cola_field_value!(concat!($prefix, $x), $t $(, $default)?),]
+            @checks [$($check)* let $n = match $crate::__cola_field_result!(concat!($prefix, $x), $t $(, $default)?) { Ok(value) => Some(value), Err(err) => { $errors.push(err); None } };]
+            @names [$($name)* $n: $n.expect("validated above"),]
+            @reads [$($read)* $n: $crate::__cola_field_from_table!(&$table, concat!($prefix, $x), $t $(, $default)?),]
+            @input
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// Implementation detail of <make_conf>: resolves a single field's value,
+/// taking the optional default into account. Not part of the public API.
+macro_rules! __cola_field_value {
+    ($($args:tt)*) => {
+        $crate::__cola_field_result!($($args)*)?
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// Implementation detail of <make_conf>: resolves a single field to a `Result`
+/// without ever returning early, so callers can either propagate it with `?`
+/// (<make_conf::new>) or collect it alongside the other fields (<make_conf::new_all>).
+/// Not part of the public API.
+macro_rules! __cola_field_result {
+    ($x:expr, Option<$t:ty>) => {
+        match $crate::parse_env($x) {
+            Ok(raw) => $crate::convert::<$t>(raw).map(Some),
+            Err($crate::ConfigError::ConfigMissing(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    };
+    ($x:expr, Vec<$t:ty>) => {
+        $crate::parse_env($x).and_then(|raw| $crate::convert_list::<$t>(&raw, ','))
+    };
+    ($x:expr, Vec<$t:ty>, sep $sep:expr) => {
+        $crate::parse_env($x).and_then(|raw| $crate::convert_list::<$t>(&raw, $sep))
+    };
+    ($x:expr, Vec<$t:ty>, sep $sep:expr, $default:expr) => {
+        match $crate::parse_env($x) {
+            Ok(raw) => $crate::convert_list::<$t>(&raw, $sep),
+            Err($crate::ConfigError::ConfigMissing(_)) => Ok($default),
+            Err(err) => Err(err),
+        }
+    };
+    ($x:expr, Vec<$t:ty>, $default:expr) => {
+        match $crate::parse_env($x) {
+            Ok(raw) => $crate::convert_list::<$t>(&raw, ','),
+            Err($crate::ConfigError::ConfigMissing(_)) => Ok($default),
+            Err(err) => Err(err),
+        }
+    };
+    ($x:expr, $t:ty) => {
+        $crate::parse_env($x).and_then($crate::convert::<$t>)
+    };
+    ($x:expr, $t:ty, $default:expr) => {
+        match $crate::parse_env($x) {
+            Ok(raw) => $crate::convert::<$t>(raw),
+            Err($crate::ConfigError::ConfigMissing(_)) => Ok($default),
+            Err(err) => Err(err),
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+/// Implementation detail of <make_conf>: resolves a single field's value from a
+/// layered file table, letting a present environment variable take precedence and
+/// an optional default apply last. Not part of the public API.
+macro_rules! __cola_field_from_table {
+    ($table:expr, $x:expr, Option<$t:ty>) => {
+        match $crate::resolve_layered($table, $x) {
+            Ok(raw) => Some($crate::convert::<$t>(raw)?),
+            Err($crate::ConfigError::ConfigMissing(_)) => None,
+            Err(err) => return Err(err),
+        }
+    };
+    ($table:expr, $x:expr, Vec<$t:ty>) => {
+        $crate::convert_list::<$t>(&$crate::resolve_layered($table, $x)?, ',')?
+    };
+    ($table:expr, $x:expr, Vec<$t:ty>, sep $sep:expr) => {
+        $crate::convert_list::<$t>(&$crate::resolve_layered($table, $x)?, $sep)?
+    };
+    ($table:expr, $x:expr, Vec<$t:ty>, sep $sep:expr, $default:expr) => {
+        match $crate::resolve_layered($table, $x) {
+            Ok(raw) => $crate::convert_list::<$t>(&raw, $sep)?,
+            Err($crate::ConfigError::ConfigMissing(_)) => $default,
+            Err(err) => return Err(err),
+        }
+    };
+    ($table:expr, $x:expr, Vec<$t:ty>, $default:expr) => {
+        match $crate::resolve_layered($table, $x) {
+            Ok(raw) => $crate::convert_list::<$t>(&raw, ',')?,
+            Err($crate::ConfigError::ConfigMissing(_)) => $default,
+            Err(err) => return Err(err),
+        }
+    };
+    ($table:expr, $x:expr, $t:ty) => {
+        $crate::convert::<$t>($crate::resolve_layered($table, $x)?)?
+    };
+    ($table:expr, $x:expr, $t:ty, $default:expr) => {
+        match $crate::resolve_layered($table, $x) {
+            Ok(raw) => $crate::convert::<$t>(raw)?,
+            Err($crate::ConfigError::ConfigMissing(_)) => $default,
+            Err(err) => return Err(err),
+        }
+    };
 }
 
 #[derive(Debug)]
@@ -121,6 +525,8 @@ macro_rules! make_conf {
 pub enum ConfigError {
     ConfigMissing(String),
     InvalidData(String),
+    /// Every error encountered while evaluating all fields; see <Configuration::new_all>.
+    Multiple(Vec<Self>),
 }
 
 /// Convert a String into a given type.
@@ -136,6 +542,25 @@ where
         .map_or_else(|_| Err(ConfigError::InvalidData(source)), Ok)
 }
 
+/// Split a String on `separator` and run each element through <convert>.
+///
+/// Surrounding whitespace around each element is trimmed before parsing, and
+/// empty elements are skipped, so `"a, b,,c"` is read the same as `"a,b,c"`.
+///
+/// # Errors
+/// - <ConfigError::InvalidData>, naming the offending element
+pub fn convert_list<T>(source: &str, separator: char) -> Result<Vec<T>, ConfigError>
+where
+    T: core::str::FromStr,
+{
+    source
+        .split(separator)
+        .map(str::trim)
+        .filter(|element| !element.is_empty())
+        .map(|element| convert::<T>(element.to_string()))
+        .collect()
+}
+
 /// Load the data stored in a given environment variable.
 ///
 /// # Errors
@@ -150,6 +575,107 @@ pub fn parse_env(key: &str) -> Result<String, ConfigError> {
     )
 }
 
+/// Seed the process environment from a `.env`-style file at `path`.
+///
+/// Lines may be blank, `#`-prefixed comments, or `KEY=VALUE` pairs. Values may be
+/// wrapped in single or double quotes, which are stripped. A variable already set
+/// in the real environment is never overwritten by the file, so the environment
+/// always wins over `.env`. If `path` can't be read, this is a no-op; a missing
+/// `.env` file is not an error.
+///
+/// This is typically called through <Configuration::from_env_file> rather than
+/// directly.
+pub fn load_dotenv<P: AsRef<std::path::Path>>(path: P) {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return;
+    };
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        let key = key.trim();
+        if key.is_empty() || std::env::var(key).is_ok() {
+            continue;
+        }
+
+        std::env::set_var(key, strip_quotes(value.trim()));
+    }
+}
+
+/// Strip a single layer of matching single or double quotes from `value`.
+fn strip_quotes(value: &str) -> &str {
+    let bytes = value.as_bytes();
+    let is_quoted = bytes.len() >= 2 && (bytes[0] == b'"' || bytes[0] == b'\'') && bytes[0] == bytes[bytes.len() - 1];
+
+    if is_quoted {
+        &value[1..value.len() - 1]
+    } else {
+        value
+    }
+}
+
+/// Parse a simple `key = value` file (a safe subset of TOML) into a lookup table.
+///
+/// Lines may be blank, `#`-prefixed comments, or `key = value` pairs; values may be
+/// wrapped in single or double quotes, which are stripped. If `path` can't be read,
+/// an empty table is returned, mirroring `load_dotenv`.
+///
+/// This is typically called through <Configuration::read_from> rather than directly.
+#[must_use]
+pub fn read_table<P: AsRef<std::path::Path>>(path: P) -> std::collections::HashMap<String, String> {
+    let mut table = std::collections::HashMap::new();
+
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return table;
+    };
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        let key = key.trim();
+        if key.is_empty() {
+            continue;
+        }
+
+        table.insert(key.to_string(), strip_quotes(value.trim()).to_string());
+    }
+
+    table
+}
+
+/// Resolve a single key, letting a present environment variable take precedence
+/// over the matching entry in `table`.
+///
+/// # Errors
+/// - <ConfigError::ConfigMissing>, when the key is in neither the environment nor `table`
+pub fn resolve_layered<S: std::hash::BuildHasher>(
+    table: &std::collections::HashMap<String, String, S>,
+    key: &str,
+) -> Result<String, ConfigError> {
+    if let Ok(value) = std::env::var(key) {
+        return Ok(value);
+    }
+
+    table
+        .get(key)
+        .cloned()
+        .ok_or_else(|| ConfigError::ConfigMissing(key.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,6 +754,254 @@ mod tests {
         }
     }
 
+    #[test]
+    fn it_falls_back_to_the_default_when_missing() {
+        #![allow(clippy::items_after_statements)]
+        env::remove_var("DEFINITELY_NOT_SET");
+
+        make_conf! ["DEFINITELY_NOT_SET" => port: u16 = 8080];
+
+        let conf = Configuration::new().unwrap();
+        assert_eq!(conf.port, 8080);
+    }
+
+    #[test]
+    fn it_still_fails_on_invalid_data_when_a_default_is_present() {
+        #![allow(clippy::items_after_statements)]
+        env::set_var("TEST_TRUE_ENV_KEY", "potato");
+
+        make_conf! ["TEST_TRUE_ENV_KEY" => test_boolean: bool = true];
+
+        match Configuration::new() {
+            Err(ConfigError::InvalidData(string)) => assert!(string.contains("potato")),
+            Err(err) => panic!("should not panic {err:?}"),
+            Ok(_) => panic!("should not be ok"),
+        }
+    }
+
+    #[test]
+    fn it_loads_values_from_a_dotenv_file() {
+        #![allow(clippy::items_after_statements)]
+        let path = env::temp_dir().join("cola_test_loads_values.env");
+        std::fs::write(
+            &path,
+            "# a comment\n\nDOTENV_STRING=hello world\nDOTENV_QUOTED=\"quoted value\"\n",
+        )
+        .unwrap();
+        env::remove_var("DOTENV_STRING");
+        env::remove_var("DOTENV_QUOTED");
+
+        make_conf! [
+            "DOTENV_STRING" => dotenv_string: String,
+            "DOTENV_QUOTED" => dotenv_quoted: String
+        ];
+
+        let conf = Configuration::from_env_file(&path).unwrap();
+
+        assert_eq!(conf.dotenv_string, "hello world");
+        assert_eq!(conf.dotenv_quoted, "quoted value");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn dotenv_does_not_override_the_real_environment() {
+        #![allow(clippy::items_after_statements)]
+        let path = env::temp_dir().join("cola_test_does_not_override.env");
+        std::fs::write(&path, "DOTENV_OVERRIDE_KEY=from_file\n").unwrap();
+        env::set_var("DOTENV_OVERRIDE_KEY", "from_env");
+
+        make_conf! ["DOTENV_OVERRIDE_KEY" => value: String];
+
+        let conf = Configuration::from_env_file(&path).unwrap();
+
+        assert_eq!(conf.value, "from_env");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn it_parses_a_list_field() {
+        #![allow(clippy::items_after_statements)]
+        env::set_var("TEST_LIST_ENV_KEY", "one, two,three");
+
+        make_conf! ["TEST_LIST_ENV_KEY" => items: Vec<String>];
+
+        let conf = Configuration::new().unwrap();
+        assert_eq!(conf.items, vec!["one", "two", "three"]);
+    }
+
+    #[test]
+    fn it_parses_a_list_field_with_a_custom_separator() {
+        #![allow(clippy::items_after_statements)]
+        env::set_var("TEST_SEMICOLON_LIST_ENV_KEY", "one;two;three");
+
+        make_conf! ["TEST_SEMICOLON_LIST_ENV_KEY" => items: Vec<String> sep ';'];
+
+        let conf = Configuration::new().unwrap();
+        assert_eq!(conf.items, vec!["one", "two", "three"]);
+    }
+
+    #[test]
+    fn it_falls_back_to_the_default_list_when_missing() {
+        #![allow(clippy::items_after_statements)]
+        env::remove_var("DEFINITELY_NOT_SET_LIST");
+
+        make_conf! ["DEFINITELY_NOT_SET_LIST" => items: Vec<u16> = vec![80, 443]];
+
+        let conf = Configuration::new().unwrap();
+        assert_eq!(conf.items, vec![80, 443]);
+    }
+
+    #[test]
+    fn it_names_the_offending_element_in_a_list() {
+        #![allow(clippy::items_after_statements)]
+        env::set_var("TEST_BAD_LIST_ENV_KEY", "1,potato,3");
+
+        make_conf! ["TEST_BAD_LIST_ENV_KEY" => items: Vec<u16>];
+
+        match Configuration::new() {
+            Err(ConfigError::InvalidData(string)) => assert!(string.contains("potato")),
+            Err(err) => panic!("should not panic {err:?}"),
+            Ok(_) => panic!("should not be ok"),
+        }
+    }
+
+    #[test]
+    fn new_all_accumulates_every_bad_field() {
+        #![allow(clippy::items_after_statements)]
+        env::remove_var("TEST_MULTI_MISSING_ONE");
+        env::remove_var("TEST_MULTI_MISSING_TWO");
+        env::set_var("TEST_MULTI_OK", "ok");
+
+        make_conf! [
+            "TEST_MULTI_MISSING_ONE" => one: String,
+            "TEST_MULTI_OK" => fine: String,
+            "TEST_MULTI_MISSING_TWO" => two: String
+        ];
+
+        match Configuration::new_all() {
+            Err(ConfigError::Multiple(errors)) => assert_eq!(errors.len(), 2),
+            Err(err) => panic!("should not panic {err:?}"),
+            Ok(_) => panic!("should not be ok"),
+        }
+    }
+
+    #[test]
+    fn new_all_still_succeeds_when_everything_is_present() {
+        #![allow(clippy::items_after_statements)]
+        env::set_var("TEST_MULTI_ALL_OK", "ok");
+
+        make_conf! ["TEST_MULTI_ALL_OK" => fine: String];
+
+        let conf = Configuration::new_all().unwrap();
+        assert_eq!(conf.fine, "ok");
+    }
+
+    #[test]
+    fn it_reads_layered_values_with_environment_precedence() {
+        #![allow(clippy::items_after_statements)]
+        let path = env::temp_dir().join("cola_test_read_from.toml");
+        std::fs::write(
+            &path,
+            "from_file_only = \"file\"\nTEST_LAYERED_OVERRIDE = \"file\"\n",
+        )
+        .unwrap();
+        env::remove_var("TEST_LAYERED_OVERRIDE_ENV");
+        env::set_var("TEST_LAYERED_OVERRIDE", "env");
+
+        make_conf! [
+            "from_file_only" => from_file_only: String,
+            "TEST_LAYERED_OVERRIDE" => overridden: String
+        ];
+
+        let conf = Configuration::read_from(&path).unwrap();
+
+        assert_eq!(conf.from_file_only, "file");
+        assert_eq!(conf.overridden, "env");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn read_from_falls_back_to_the_default_when_absent_everywhere() {
+        #![allow(clippy::items_after_statements)]
+        let path = env::temp_dir().join("cola_test_read_from_default.toml");
+        std::fs::write(&path, "# empty\n").unwrap();
+        env::remove_var("TEST_LAYERED_DEFAULT");
+
+        make_conf! ["TEST_LAYERED_DEFAULT" => port: u16 = 8080];
+
+        let conf = Configuration::read_from(&path).unwrap();
+        assert_eq!(conf.port, 8080);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn it_resolves_an_optional_field_to_none_when_missing() {
+        #![allow(clippy::items_after_statements)]
+        env::remove_var("TEST_OPTIONAL_MISSING");
+
+        make_conf! ["TEST_OPTIONAL_MISSING" => flag: Option<bool>];
+
+        let conf = Configuration::new().unwrap();
+        assert_eq!(conf.flag, None);
+    }
+
+    #[test]
+    fn it_resolves_an_optional_field_to_some_when_present() {
+        #![allow(clippy::items_after_statements)]
+        env::set_var("TEST_OPTIONAL_PRESENT", "true");
+
+        make_conf! ["TEST_OPTIONAL_PRESENT" => flag: Option<bool>];
+
+        let conf = Configuration::new().unwrap();
+        assert_eq!(conf.flag, Some(true));
+    }
+
+    #[test]
+    fn it_still_fails_an_optional_field_on_invalid_data() {
+        #![allow(clippy::items_after_statements)]
+        env::set_var("TEST_OPTIONAL_INVALID", "potato");
+
+        make_conf! ["TEST_OPTIONAL_INVALID" => flag: Option<bool>];
+
+        match Configuration::new() {
+            Err(ConfigError::InvalidData(string)) => assert!(string.contains("potato")),
+            Err(err) => panic!("should not panic {err:?}"),
+            Ok(_) => panic!("should not be ok"),
+        }
+    }
+
+    #[test]
+    fn it_applies_a_shared_prefix_to_every_key() {
+        #![allow(clippy::items_after_statements)]
+        env::set_var("TEST_PREFIX_NAME", "Brad");
+        env::set_var("TEST_PREFIX_AGE", "20");
+
+        make_conf! [
+            prefix "TEST_PREFIX_";
+            "NAME" => name: String,
+            "AGE" => age: u32
+        ];
+
+        let conf = Configuration::new().unwrap();
+        assert_eq!(conf.name, "Brad");
+        assert_eq!(conf.age, 20);
+    }
+
+    #[test]
+    fn it_still_works_without_a_prefix() {
+        #![allow(clippy::items_after_statements)]
+        env::set_var("TEST_NO_PREFIX_KEY", "unprefixed");
+
+        make_conf! ["TEST_NO_PREFIX_KEY" => value: String];
+
+        let conf = Configuration::new().unwrap();
+        assert_eq!(conf.value, "unprefixed");
+    }
+
     #[test]
     fn invalid_data_returns_apropos_result() {
         #![allow(dead_code)]